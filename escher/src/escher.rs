@@ -1,8 +1,11 @@
-use std::future::Future;
-use std::pin::Pin;
-use std::sync::atomic::{AtomicPtr, Ordering};
-use std::sync::Arc;
-use std::task::Context;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::task::{Context, Poll};
 
 use futures_task::noop_waker;
 
@@ -22,6 +25,13 @@ use futures_task::noop_waker;
 ///     type Out = Foo<'a>; // CORRECT
 /// }
 /// ```
+///
+/// # Safety
+///
+/// Implementers must guarantee that `Self` and `Out` are the exact same type except for the
+/// lifetimes being rebound to `'a`. Escher transmutes between `Self` and `Out` through the raw
+/// pointer it manages internally, so any other difference (a different field layout, a narrowed
+/// or widened type) is immediate undefined behavior.
 pub unsafe trait RebindTo<'a> {
     type Out: 'a;
 }
@@ -57,9 +67,19 @@ pub type Rebind<'a, T> = <T as RebindTo<'a>>::Out;
 /// aid of the async/await machinery of rustc, see Escher::new.
 pub struct Escher<T> {
     _fut: Pin<Box<dyn Future<Output = ()>>>,
-    ptr: *mut T,
+    ptr: NonNull<T>,
+    // `Escher` owns the `T` that `ptr` points to (it lives inside `_fut`), so this marker makes
+    // the compiler treat it that way for variance and drop-check purposes, the same way `Unique`
+    // does for the standard collections.
+    _marker: PhantomData<T>,
 }
 
+// SAFETY: sharing &Escher<T> across threads lets each thread call as_ref() concurrently, which
+// hands out `&Rebind<'a, T>` to each of them at once. That is only sound if the projection can
+// itself be shared across threads, mirroring the bound the standard library puts on `RefCell`
+// (Send but not Sync) versus `Arc` (Sync requires T: Sync).
+unsafe impl<T: Rebindable> Sync for Escher<T> where for<'a> Rebind<'a, T>: Sync {}
+
 impl<T: Rebindable> Escher<T> {
     /// Construct a self referencial struct using the provided closure. The user is expected to
     /// construct the desired data and references to them in the async stack and capture the
@@ -75,17 +95,99 @@ impl<T: Rebindable> Escher<T> {
     ///     r.capture(sparkle_heart).await;
     /// });
     ///
-    /// assert_eq!("ðŸ’–", *escher_heart.as_ref());
+    /// assert_eq!("💖", *escher_heart.as_ref());
     /// ```
     pub fn new<B, F>(builder: B) -> Self
     where
         B: FnOnce(Capturer<T>) -> F,
         F: Future<Output = ()> + 'static,
     {
-        let ptr = Arc::new(AtomicPtr::new(std::ptr::null_mut()));
+        let ptr = Arc::new(AtomicPtr::new(core::ptr::null_mut()));
+        let r = Capturer { ptr: ptr.clone() };
+        let fut = Box::pin(builder(r));
+
+        Self::from_polled(fut, ptr)
+    }
+
+    /// Like [Escher::new], but the boxed future is required to be `Send` so that the resulting
+    /// [SendEscher<T>] can itself be sent to another thread.
+    ///
+    /// ```rust
+    /// use escher::Escher;
+    ///
+    /// let escher_heart = Escher::new_send(|r| async move {
+    ///     let data: Vec<u8> = vec![240, 159, 146, 150];
+    ///     let sparkle_heart = std::str::from_utf8(&data).unwrap();
+    ///
+    ///     r.capture(sparkle_heart).await;
+    /// });
+    ///
+    /// assert_eq!("💖", *escher_heart.as_ref());
+    /// ```
+    pub fn new_send<B, F>(builder: B) -> SendEscher<T>
+    where
+        B: FnOnce(Capturer<T>) -> F,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let ptr = Arc::new(AtomicPtr::new(core::ptr::null_mut()));
         let r = Capturer { ptr: ptr.clone() };
-        let mut fut = Box::pin(builder(r));
+        let fut: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(builder(r));
 
+        SendEscher(Self::from_polled(fut, ptr))
+    }
+
+    /// Construct a self referencial struct like [Escher::new], but let the builder `.await` real
+    /// asynchronous work (I/O, a timer, a channel, ...) before it calls `r.capture(...)`.
+    ///
+    /// Unlike [Escher::new], which polls the builder exactly once with a no-op waker, the future
+    /// returned here forwards the caller's real `Context` into the builder on every poll, so it
+    /// can be driven by an executor until it reaches its capture point.
+    ///
+    /// ```rust
+    /// use escher::Escher;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll};
+    ///
+    /// # fn block_on<F: std::future::Future>(mut fut: F) -> F::Output {
+    /// #     let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+    /// #     let waker = futures_task::noop_waker();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     loop {
+    /// #         if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+    /// #             return v;
+    /// #         }
+    /// #     }
+    /// # }
+    /// let escher_heart = block_on(Escher::new_async(|r| async move {
+    ///     let data: Vec<u8> = vec![240, 159, 146, 150];
+    ///     let sparkle_heart = std::str::from_utf8(&data).unwrap();
+    ///
+    ///     r.capture(sparkle_heart).await;
+    /// }));
+    ///
+    /// assert_eq!("💖", *escher_heart.as_ref());
+    /// ```
+    pub fn new_async<B, F>(builder: B) -> impl Future<Output = Escher<T>>
+    where
+        B: FnOnce(Capturer<T>) -> F,
+        F: Future<Output = ()> + 'static,
+    {
+        let ptr = Arc::new(AtomicPtr::new(core::ptr::null_mut()));
+        let r = Capturer { ptr: ptr.clone() };
+        let fut = Box::pin(builder(r));
+
+        NewAsync {
+            fut: Some(fut),
+            ptr,
+        }
+    }
+
+    /// Shared implementation of [Escher::new] and [Escher::new_send]: poll the already boxed
+    /// builder future once and validate that it captured a pointer into its own stack.
+    fn from_polled(
+        mut fut: Pin<Box<dyn Future<Output = ()> + 'static>>,
+        ptr: Arc<AtomicPtr<T>>,
+    ) -> Self {
         let waker = noop_waker();
         let mut cx = Context::from_waker(&waker);
         let _ = fut.as_mut().poll(&mut cx);
@@ -98,14 +200,19 @@ impl<T: Rebindable> Escher<T> {
 
         let ptr = ptr.load(Ordering::Acquire);
 
-        let low = &*fut as *const _ as usize;
-        let high = low + std::mem::size_of_val(&*fut);
+        // Cast through a thin pointer first: `&*fut` is a fat pointer (it carries the vtable for
+        // `dyn Future`), and only its data address is meaningful as a stack range bound.
+        let low = (&*fut as *const dyn Future<Output = ()>).cast::<u8>() as usize;
+        let high = low + core::mem::size_of_val(&*fut);
         // Adversarial code can attempt to capture a value that does not live on the async stack
         assert!(
             low <= ptr as usize && ptr as usize <= high,
             "captured value outside of async stack. Did you run capture() in a non async function?"
         );
 
+        // `capture_ref` only ever stores a pointer derived from `&mut T`, so it can never be null.
+        let ptr = NonNull::new(ptr).expect("captured pointer is unexpectedly null");
+
         // SAFETY: At this point we know that:
         // 1. We are given a future that has no external references because it is 'static
         // 2. We have a pointer that points into the state of the future
@@ -115,11 +222,18 @@ impl<T: Rebindable> Escher<T> {
         //    b. The strong count of AtomicPtr is 2, so the async stack is in Capturer::capture_ref because:
         //       Î±. Capturer is not Clone, so one cannot fake the increased refcount
         //       Î². Capturer::capture consumes Capturer so when the function returns the Arc will be dropped
-        Escher { _fut: fut, ptr }
+        Escher {
+            _fut: fut,
+            ptr,
+            _marker: PhantomData,
+        }
     }
 
     /// Get a shared reference to the inner T with its lifetime bound to &self
-    pub fn as_ref<'a>(&'a self) -> &Rebind<'a, T> {
+    // `Escher::as_ref` intentionally doesn't implement `std::convert::AsRef`: its return type is
+    // the rebound projection `Rebind<'a, T>`, not `T` itself, so the trait doesn't apply here.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref<'a>(&'a self) -> &'a Rebind<'a, T> {
         // SAFETY
         // Validity of reference
         //    self.ptr points to a valid instance of T in side of self._fut (see safety argument in
@@ -128,13 +242,104 @@ impl<T: Rebindable> Escher<T> {
         //    The resulting reference is has all its lifetimes bound to the lifetime of self that
         //    contains _fut that contains all the data that ptr could be referring to because it's
         //    a 'static Future
-        unsafe { &*(self.ptr as *mut _) }
+        unsafe { self.ptr.cast().as_ref() }
     }
 
     /// Get a mut reference to the inner T with its lifetime bound to &mut self
-    pub fn as_mut<'a>(&'a mut self) -> &mut Rebind<'a, T> {
+    // See the note on `Escher::as_ref`: the return type rules out `std::convert::AsMut` here too.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_mut<'a>(&'a mut self) -> &'a mut Rebind<'a, T> {
         // SAFETY: see safety argument of Self::as_ref
-        unsafe { &mut *(self.ptr as *mut _) }
+        unsafe { self.ptr.cast().as_mut() }
+    }
+}
+
+/// An [Escher<T>] that is guaranteed to have been built from a `Send` builder future (see
+/// [Escher::new_send]), and can therefore be sent to another thread itself.
+///
+/// `Escher<T>`'s `_fut` field is type-erased to a plain `dyn Future<Output = ()>` regardless of
+/// which constructor built it, which loses the `Send` bound `new_send` established at the call
+/// site. Wrapping the result in this distinct type recovers that guarantee at the type level: the
+/// only way to obtain a `SendEscher<T>` is through `Escher::new_send`, so its mere existence proves
+/// the boxed future was `Send`.
+pub struct SendEscher<T>(Escher<T>);
+
+impl<T> core::ops::Deref for SendEscher<T> {
+    type Target = Escher<T>;
+
+    fn deref(&self) -> &Escher<T> {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for SendEscher<T> {
+    fn deref_mut(&mut self) -> &mut Escher<T> {
+        &mut self.0
+    }
+}
+
+// SAFETY: the only way to construct a `SendEscher<T>` is through `Escher::new_send`, which
+// requires the builder future to be `Send`, so the boxed future this wraps is genuinely sendable.
+unsafe impl<T> Send for SendEscher<T> {}
+
+// SAFETY: see the `Sync` impl on `Escher<T>`; the same argument applies here.
+unsafe impl<T: Rebindable> Sync for SendEscher<T> where for<'a> Rebind<'a, T>: Sync {}
+
+/// The future returned by [Escher::new_async]. Drives the builder future with the caller's real
+/// waker and resolves as soon as the capture point is reached.
+struct NewAsync<T> {
+    fut: Option<Pin<Box<dyn Future<Output = ()>>>>,
+    ptr: Arc<AtomicPtr<T>>,
+}
+
+impl<T> Future for NewAsync<T> {
+    type Output = Escher<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let fut = this.fut.as_mut().expect("NewAsync polled after completion");
+        let poll_result = fut.as_mut().poll(cx);
+
+        // `Capturer::capture_ref` stores the pointer before awaiting `pending()` forever, so a
+        // non-null pointer means the capture point has been reached regardless of what the inner
+        // poll just returned.
+        let ptr = this.ptr.load(Ordering::Acquire);
+        if !ptr.is_null() {
+            // Adversarial code can attempt to poll a capture by hand and then drop it
+            assert!(
+                Arc::strong_count(&this.ptr) == 2,
+                "capture no longer live. Did you forget to .await the result of capture()?"
+            );
+
+            let fut = this.fut.take().unwrap();
+
+            // Cast through a thin pointer first, see the equivalent assertion in `from_polled`.
+            let low = (&*fut as *const dyn Future<Output = ()>).cast::<u8>() as usize;
+            let high = low + core::mem::size_of_val(&*fut);
+            // Adversarial code can attempt to capture a value that does not live on the async stack
+            assert!(
+                low <= ptr as usize && ptr as usize <= high,
+                "captured value outside of async stack. Did you run capture() in a non async function?"
+            );
+
+            // `ptr` is derived from `&mut T` in `capture_ref`, so it can never be null.
+            let ptr = NonNull::new(ptr).expect("captured pointer is unexpectedly null");
+
+            return Poll::Ready(Escher {
+                _fut: fut,
+                ptr,
+                _marker: PhantomData,
+            });
+        }
+
+        match poll_result {
+            // The real future registered a waker and will be polled again later.
+            Poll::Pending => Poll::Pending,
+            // Adversarial code can attempt to capture a value without awaiting on the result
+            Poll::Ready(()) => {
+                panic!("capture no longer live. Did you forget to .await the result of capture()?")
+            }
+        }
     }
 }
 
@@ -151,7 +356,7 @@ impl<StaticT> Capturer<StaticT> {
         T: RebindTo<'static, Out = StaticT>,
     {
         self.ptr.store(val as *mut _ as *mut StaticT, Ordering::Release);
-        std::future::pending::<()>().await;
+        core::future::pending::<()>().await;
     }
 
     /// Captures the passed value into a future that never resolves.
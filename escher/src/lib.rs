@@ -1,5 +1,8 @@
 //! > Self-referencial structs using async stacks
 //!
+//! The `std` feature is on by default. Disabling it builds the crate under `#![no_std]` against
+//! `alloc` alone, for embedded and kernel-style environments.
+//!
 //! Escher is an extremely simple library providing a safe and sound API to build self-referencial
 //! structs. It works by (ab)using the async await trasformation of rustc. If you'd like to know
 //! more about the inner workings please take a look at the [How it
@@ -22,7 +25,7 @@
 //! capturer `r` that has a single [capture()](Capturer::capture) method that consumes `r`.
 //!
 //! > **Note:** It is important to `.await` the result `.capture()` in order for escher to correctly
-//! initialize your struct.
+//! > initialize your struct.
 //!
 //! Once all the data and references are created you can capture the desired ones. Simple
 //! references to owned data can be captured directly (see first example).
@@ -129,6 +132,10 @@
 //! assert_eq!(84.0, *my_value.as_ref().float_ref);
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod escher;
 mod tests;
 
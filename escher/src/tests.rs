@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "std"))]
 use super::*;
 use crate as escher;
 
@@ -103,6 +103,82 @@ fn capture_union() {
     }
 }
 
+#[test]
+fn send_across_threads() {
+    /// Holds a vector and a str reference to the data of the vector
+    #[derive(Rebindable)]
+    struct VecStr<'a> {
+        data: &'a Vec<u8>,
+        s: &'a str,
+    }
+
+    let escher_heart = Escher::new_send(|r| async move {
+        let data: Vec<u8> = vec![240, 159, 146, 150];
+        let sparkle_heart = std::str::from_utf8(&data).unwrap();
+
+        r.capture(VecStr {
+            data: &data,
+            s: sparkle_heart,
+        })
+        .await;
+    });
+
+    let escher_heart = std::thread::spawn(move || escher_heart).join().unwrap();
+
+    assert_eq!(240, escher_heart.as_ref().data[0]);
+    assert_eq!("💖", escher_heart.as_ref().s);
+}
+
+#[test]
+fn new_async_awaits_real_future() {
+    use futures_task::noop_waker;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    /// A future that is `Pending` exactly once, to simulate awaiting real asynchronous work
+    /// (I/O, a timer, a channel, ...) before the capture point.
+    struct YieldOnce(bool);
+
+    impl std::future::Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    fn block_on<F: std::future::Future>(mut fut: F) -> F::Output {
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+                return v;
+            }
+        }
+    }
+
+    #[derive(Rebindable)]
+    struct MyStr<'a>(&'a str);
+
+    let escher_heart = block_on(Escher::new_async(|r| async move {
+        let data: Vec<u8> = vec![240, 159, 146, 150];
+        let sparkle_heart = std::str::from_utf8(&data).unwrap();
+
+        YieldOnce(false).await;
+
+        r.capture(MyStr(sparkle_heart)).await;
+    }));
+
+    assert_eq!("💖", escher_heart.as_ref().0);
+}
+
 #[test]
 fn it_works() {
     /// Holds a vector and a str reference to the data of the vector